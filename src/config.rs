@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use directories::ProjectDirs;
+use serde::Deserialize;
+
+/// A named user action a key can be bound to, independent of the physical
+/// key used to trigger it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Quit,
+    Up,
+    Down,
+    Filter,
+    KillTerm,
+    KillForce,
+    CycleSort,
+}
+
+/// Resolves key presses to [`Action`]s, starting from sane defaults and
+/// optionally overridden by the config file.
+pub struct Keymap {
+    bindings: HashMap<(KeyCode, KeyModifiers), Action>,
+}
+
+impl Keymap {
+    pub fn resolve(&self, code: KeyCode, modifiers: KeyModifiers) -> Option<Action> {
+        self.bindings.get(&(code, modifiers)).copied()
+    }
+
+    fn bind(&mut self, binding: &str, action: Action) {
+        if let Some(key) = parse_key(binding) {
+            self.bindings.insert(key, action);
+        }
+    }
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let mut bindings = HashMap::new();
+        bindings.insert((KeyCode::Char('q'), KeyModifiers::NONE), Action::Quit);
+        bindings.insert((KeyCode::Down, KeyModifiers::NONE), Action::Down);
+        bindings.insert((KeyCode::Up, KeyModifiers::NONE), Action::Up);
+        bindings.insert((KeyCode::Char('/'), KeyModifiers::NONE), Action::Filter);
+        bindings.insert((KeyCode::Char('k'), KeyModifiers::NONE), Action::KillTerm);
+        bindings.insert((KeyCode::Char('K'), KeyModifiers::NONE), Action::KillForce);
+        bindings.insert((KeyCode::Char('s'), KeyModifiers::NONE), Action::CycleSort);
+        Keymap { bindings }
+    }
+}
+
+/// Parses bindings like `"q"`, `"ctrl+k"`, `"shift+down"` into a
+/// `(KeyCode, KeyModifiers)` pair. Unrecognized strings are ignored so a
+/// typo in the config file can't panic the app; it just falls back silently.
+fn parse_key(binding: &str) -> Option<(KeyCode, KeyModifiers)> {
+    let mut modifiers = KeyModifiers::NONE;
+    let mut parts = binding.split('+').peekable();
+    let mut last = parts.next()?;
+    for part in parts {
+        modifiers |= match last.to_lowercase().as_str() {
+            "ctrl" => KeyModifiers::CONTROL,
+            "shift" => KeyModifiers::SHIFT,
+            "alt" => KeyModifiers::ALT,
+            _ => return None,
+        };
+        last = part;
+    }
+
+    let code = match last.to_lowercase().as_str() {
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "esc" | "escape" => KeyCode::Esc,
+        "enter" => KeyCode::Enter,
+        "backspace" => KeyCode::Backspace,
+        other if other.chars().count() == 1 => KeyCode::Char(last.chars().next()?),
+        _ => return None,
+    };
+    Some((code, modifiers))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortKey {
+    Port,
+    Pid,
+    ProcessName,
+    State,
+    Rate,
+}
+
+impl SortKey {
+    const CYCLE: [SortKey; 5] = [
+        SortKey::Port,
+        SortKey::Pid,
+        SortKey::ProcessName,
+        SortKey::State,
+        SortKey::Rate,
+    ];
+
+    fn next(self) -> SortKey {
+        let pos = Self::CYCLE.iter().position(|k| *k == self).unwrap_or(0);
+        Self::CYCLE[(pos + 1) % Self::CYCLE.len()]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+/// The active sort column and direction for the Processes list.
+#[derive(Debug, Clone, Copy)]
+pub struct Sorting {
+    pub key: SortKey,
+    pub direction: SortDirection,
+}
+
+impl Default for Sorting {
+    fn default() -> Self {
+        Sorting {
+            key: SortKey::Port,
+            direction: SortDirection::Ascending,
+        }
+    }
+}
+
+impl Sorting {
+    /// Advances to the next sort column, wrapping back to the first.
+    pub fn cycle(&mut self) {
+        self.key = self.key.next();
+    }
+}
+
+#[derive(Deserialize, Default)]
+struct RawConfig {
+    #[serde(default)]
+    keybindings: HashMap<String, String>,
+    sort: Option<RawSort>,
+}
+
+#[derive(Deserialize)]
+struct RawSort {
+    key: Option<SortKey>,
+    direction: Option<SortDirection>,
+}
+
+fn action_by_name(name: &str) -> Option<Action> {
+    Some(match name {
+        "quit" => Action::Quit,
+        "up" => Action::Up,
+        "down" => Action::Down,
+        "filter" => Action::Filter,
+        "kill_term" => Action::KillTerm,
+        "kill_force" => Action::KillForce,
+        "cycle_sort" => Action::CycleSort,
+        _ => return None,
+    })
+}
+
+/// Resolved keymap and sorting, built from built-in defaults overridden by
+/// whatever the config file sets.
+#[derive(Default)]
+pub struct Config {
+    pub keymap: Keymap,
+    pub sorting: Sorting,
+}
+
+/// Loads `config.toml` from the platform config dir, falling back to
+/// built-in defaults when it's absent or malformed so behavior is
+/// unchanged out of the box.
+pub fn load() -> Config {
+    let mut config = Config::default();
+
+    let Some(dirs) = ProjectDirs::from("", "", "port-folio") else {
+        return config;
+    };
+    let path = dirs.config_dir().join("config.toml");
+    let Ok(contents) = std::fs::read_to_string(&path) else {
+        return config;
+    };
+    let Ok(raw) = toml::from_str::<RawConfig>(&contents) else {
+        crate::log!(error, "config: failed to parse {}", path.display());
+        return config;
+    };
+
+    for (name, binding) in &raw.keybindings {
+        match action_by_name(name) {
+            Some(action) => config.keymap.bind(binding, action),
+            None => crate::log!(error, "config: unknown action \"{name}\""),
+        }
+    }
+
+    if let Some(sort) = raw.sort {
+        if let Some(key) = sort.key {
+            config.sorting.key = key;
+        }
+        if let Some(direction) = sort.direction {
+            config.sorting.direction = direction;
+        }
+    }
+
+    config
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_plain_char() {
+        assert_eq!(parse_key("k"), Some((KeyCode::Char('k'), KeyModifiers::NONE)));
+    }
+
+    #[test]
+    fn uppercase_char_carries_no_implicit_shift() {
+        // A normal terminal already reports Shift+K as Char('K') with no
+        // modifier, so parse_key must not OR in SHIFT itself or the default
+        // KillForce binding becomes unreachable.
+        assert_eq!(parse_key("K"), Some((KeyCode::Char('K'), KeyModifiers::NONE)));
+    }
+
+    #[test]
+    fn parses_modifier_combo() {
+        assert_eq!(
+            parse_key("ctrl+k"),
+            Some((KeyCode::Char('k'), KeyModifiers::CONTROL))
+        );
+    }
+
+    #[test]
+    fn explicit_shift_is_still_settable() {
+        assert_eq!(
+            parse_key("shift+down"),
+            Some((KeyCode::Down, KeyModifiers::SHIFT))
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_binding() {
+        assert_eq!(parse_key("nonsense"), None);
+    }
+
+    #[test]
+    fn default_killforce_binding_matches_a_plain_terminal_shift_k() {
+        let keymap = Keymap::default();
+        assert_eq!(
+            keymap.resolve(KeyCode::Char('K'), KeyModifiers::NONE),
+            Some(Action::KillForce)
+        );
+    }
+
+    #[test]
+    fn sort_key_cycles_through_all_variants_and_wraps() {
+        let mut key = SortKey::Port;
+        for _ in 0..SortKey::CYCLE.len() {
+            key = key.next();
+        }
+        assert_eq!(key, SortKey::Port);
+    }
+}
@@ -0,0 +1,176 @@
+use std::collections::{HashMap, VecDeque};
+use std::net::IpAddr;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+use trust_dns_resolver::TokioAsyncResolver;
+
+const MAX_CACHE_ENTRIES: usize = 4096;
+const LOOKUP_TIMEOUT: Duration = Duration::from_millis(500);
+
+#[derive(Clone)]
+enum CacheEntry {
+    Resolved(String),
+    Unresolved,
+}
+
+#[derive(Default)]
+struct IpTableInner {
+    entries: HashMap<IpAddr, CacheEntry>,
+    order: VecDeque<IpAddr>,
+}
+
+/// Shared, LRU-bounded cache of `IpAddr -> hostname` lookups, filled in
+/// asynchronously by [`spawn_resolver`] so the render loop never blocks on DNS.
+#[derive(Clone, Default)]
+pub struct IpTable(Arc<RwLock<IpTableInner>>);
+
+impl IpTable {
+    /// Returns the cached hostname for `ip`, if a lookup has already resolved one.
+    pub fn hostname_for(&self, ip: &IpAddr) -> Option<String> {
+        let mut inner = self.0.write().unwrap();
+        let host = match inner.entries.get(ip) {
+            Some(CacheEntry::Resolved(host)) => Some(host.clone()),
+            _ => None,
+        };
+        if host.is_some() {
+            touch(&mut inner, ip);
+        }
+        host
+    }
+
+    /// True if `ip` already has a cached result, positive or negative.
+    pub fn contains(&self, ip: &IpAddr) -> bool {
+        let mut inner = self.0.write().unwrap();
+        let present = inner.entries.contains_key(ip);
+        if present {
+            touch(&mut inner, ip);
+        }
+        present
+    }
+
+    fn insert(&self, ip: IpAddr, entry: CacheEntry) {
+        let mut inner = self.0.write().unwrap();
+        if !inner.entries.contains_key(&ip) {
+            inner.order.push_back(ip);
+            while inner.order.len() > MAX_CACHE_ENTRIES {
+                if let Some(oldest) = inner.order.pop_front() {
+                    inner.entries.remove(&oldest);
+                }
+            }
+        } else {
+            touch(&mut inner, &ip);
+        }
+        inner.entries.insert(ip, entry);
+    }
+}
+
+/// Moves `ip` to the back of the eviction order, marking it most-recently-used.
+fn touch(inner: &mut IpTableInner, ip: &IpAddr) {
+    if let Some(pos) = inner.order.iter().position(|cached| cached == ip) {
+        inner.order.remove(pos);
+        inner.order.push_back(*ip);
+    }
+}
+
+/// Spawns the background resolver task and returns a channel to feed it
+/// addresses that still need a PTR lookup. All resolution happens off the
+/// render loop; `terminal.draw` never waits on a DNS response.
+pub fn spawn_resolver(table: IpTable) -> mpsc::UnboundedSender<IpAddr> {
+    let (tx, mut rx) = mpsc::unbounded_channel::<IpAddr>();
+
+    tokio::spawn(async move {
+        let resolver = match TokioAsyncResolver::tokio_from_system_conf() {
+            Ok(resolver) => resolver,
+            Err(e) => {
+                crate::log!(error, "dns: failed to initialize resolver: {e}");
+                return;
+            }
+        };
+
+        while let Some(ip) = rx.recv().await {
+            if table.contains(&ip) {
+                continue;
+            }
+
+            let entry = match tokio::time::timeout(LOOKUP_TIMEOUT, resolver.reverse_lookup(ip)).await
+            {
+                Ok(Ok(lookup)) => lookup
+                    .iter()
+                    .next()
+                    .map(|name| CacheEntry::Resolved(name.to_string().trim_end_matches('.').to_string()))
+                    .unwrap_or(CacheEntry::Unresolved),
+                Ok(Err(e)) => {
+                    crate::log!(error, "dns: lookup for {ip} failed: {e}");
+                    CacheEntry::Unresolved
+                }
+                Err(_) => {
+                    crate::log!(error, "dns: lookup for {ip} timed out");
+                    CacheEntry::Unresolved
+                }
+            };
+            table.insert(ip, entry);
+        }
+    });
+
+    tx
+}
+
+/// Queues every address in `ips` that isn't already cached onto the resolver
+/// channel, so `App::update` can call this each tick without re-querying
+/// addresses that already have a result (positive or negative).
+pub fn queue_unresolved<'a>(
+    table: &IpTable,
+    tx: &mpsc::UnboundedSender<IpAddr>,
+    ips: impl Iterator<Item = &'a IpAddr>,
+) {
+    for ip in ips {
+        if !table.contains(ip) {
+            let _ = tx.send(*ip);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(n: u32) -> IpAddr {
+        IpAddr::from(n.to_be_bytes())
+    }
+
+    #[test]
+    fn resolved_entries_are_returned_by_hostname_for() {
+        let table = IpTable::default();
+        table.insert(ip(1), CacheEntry::Resolved("host.example".to_string()));
+        assert_eq!(table.hostname_for(&ip(1)), Some("host.example".to_string()));
+    }
+
+    #[test]
+    fn unresolved_entries_are_cached_but_have_no_hostname() {
+        let table = IpTable::default();
+        table.insert(ip(1), CacheEntry::Unresolved);
+        assert!(table.contains(&ip(1)));
+        assert_eq!(table.hostname_for(&ip(1)), None);
+    }
+
+    #[test]
+    fn eviction_is_least_recently_used_not_fifo() {
+        let table = IpTable::default();
+
+        for i in 0..MAX_CACHE_ENTRIES as u32 {
+            table.insert(ip(i), CacheEntry::Unresolved);
+        }
+        // Touch the oldest entry so it becomes the most recently used one.
+        assert!(table.contains(&ip(0)));
+
+        // Inserting one more entry pushes the table over capacity; the
+        // least-recently-used entry (ip(1), not the touched ip(0)) must be
+        // the one evicted.
+        table.insert(ip(MAX_CACHE_ENTRIES as u32), CacheEntry::Unresolved);
+
+        assert!(table.contains(&ip(0)), "recently touched entry was evicted");
+        assert!(!table.contains(&ip(1)), "least-recently-used entry should have been evicted");
+    }
+}
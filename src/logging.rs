@@ -0,0 +1,117 @@
+use std::collections::VecDeque;
+use std::fs::File;
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use simplelog::{Config, LevelFilter, WriteLogger};
+
+const MAX_LOG_LINES: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Info,
+    Error,
+}
+
+#[derive(Clone)]
+pub struct LogEntry {
+    pub level: Level,
+    pub line: String,
+}
+
+fn buffer() -> &'static Mutex<VecDeque<LogEntry>> {
+    static BUFFER: OnceLock<Mutex<VecDeque<LogEntry>>> = OnceLock::new();
+    BUFFER.get_or_init(|| Mutex::new(VecDeque::new()))
+}
+
+/// Tees subsequent log lines to `path` in addition to the in-memory ring
+/// buffer the Logs panel reads from. Call once at startup when `--log-to`
+/// is given; without it, lines only ever live in memory.
+pub fn init_file_logger(path: &Path) -> std::io::Result<()> {
+    let file = File::create(path)?;
+    WriteLogger::init(LevelFilter::Info, Config::default(), file).map_err(std::io::Error::other)
+}
+
+/// Pushes a timestamped line into the ring buffer (evicting the oldest once
+/// full) and, if a file logger was initialized, tees it there too. Use the
+/// [`crate::log`] macro instead of calling this directly.
+pub fn push(level: Level, line: String) {
+    match level {
+        Level::Info => log::info!("{line}"),
+        Level::Error => log::error!("{line}"),
+    }
+
+    let mut buffer = buffer().lock().unwrap();
+    buffer.push_back(LogEntry {
+        level,
+        line: format!("{} {}", timestamp(), line),
+    });
+    while buffer.len() > MAX_LOG_LINES {
+        buffer.pop_front();
+    }
+}
+
+/// The most recent `n` log lines, oldest first.
+pub fn recent(n: usize) -> Vec<LogEntry> {
+    let buffer = buffer().lock().unwrap();
+    let start = buffer.len().saturating_sub(n);
+    buffer.iter().skip(start).cloned().collect()
+}
+
+fn timestamp() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    format!("[{secs}]")
+}
+
+/// Pushes a line into the Logs panel's ring buffer: `log!(info, "...")` or
+/// `log!(error, "...")`, with `format!`-style arguments.
+#[macro_export]
+macro_rules! log {
+    (info, $($arg:tt)*) => {
+        $crate::logging::push($crate::logging::Level::Info, format!($($arg)*))
+    };
+    (error, $($arg:tt)*) => {
+        $crate::logging::push($crate::logging::Level::Error, format!($($arg)*))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `buffer()` is one process-wide ring buffer, so tests that push into it
+    // must not run concurrently with each other.
+    static TEST_LOCK: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn recent_returns_pushed_lines_oldest_first() {
+        let _guard = TEST_LOCK.lock().unwrap();
+        push(Level::Info, "recent_returns_pushed_lines_oldest_first: first".to_string());
+        push(Level::Error, "recent_returns_pushed_lines_oldest_first: second".to_string());
+
+        let lines = recent(2);
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].line.ends_with("first"));
+        assert_eq!(lines[0].level, Level::Info);
+        assert!(lines[1].line.ends_with("second"));
+        assert_eq!(lines[1].level, Level::Error);
+    }
+
+    #[test]
+    fn buffer_eviction_caps_at_max_log_lines() {
+        let _guard = TEST_LOCK.lock().unwrap();
+        for i in 0..MAX_LOG_LINES + 10 {
+            push(Level::Info, format!("buffer_eviction_caps_at_max_log_lines: {i}"));
+        }
+
+        let lines = recent(MAX_LOG_LINES + 10);
+        assert_eq!(lines.len(), MAX_LOG_LINES);
+        // The oldest 10 of this test's own pushes should have been evicted,
+        // leaving the most recent MAX_LOG_LINES lines ending with the last one.
+        assert!(lines.last().unwrap().line.ends_with(&format!("{}", MAX_LOG_LINES + 9)));
+    }
+}
@@ -1,5 +1,6 @@
-use std::{io, time::Duration};
-use tokio::time::interval;
+use std::{collections::HashMap, io, net::IpAddr, time::Duration};
+use clap::Parser;
+use tokio::{sync::mpsc, time::interval};
 
 use ratatui::{
     prelude::*,
@@ -14,23 +15,112 @@ use crossterm::{
 use netstat2::{get_sockets_info, AddressFamilyFlags, ProtocolFlags, ProtocolSocketInfo, SocketInfo, error::Error as NetstatError};
 use sysinfo::System;
 
+mod config;
+mod dns;
+mod logging;
+mod network;
 mod ui;
+use config::Action;
+use network::{LocalSocket, Protocol, Sniffer};
 use ui::stateful_list::StatefulList;
 
+const TICK_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Command-line options.
+#[derive(Parser)]
+#[command(name = "port-folio")]
+struct Cli {
+    /// Network interface to sniff for bandwidth stats. Defaults to the
+    /// first interface that's up and not loopback.
+    #[arg(short, long)]
+    interface: Option<String>,
+
+    /// Tee log output to this file in addition to the in-app Logs panel.
+    #[arg(long)]
+    log_to: Option<std::path::PathBuf>,
+
+    /// Stream connection snapshots to stdout as TSV instead of drawing the
+    /// TUI. For pipelines, SSH sessions without a TTY, and periodic captures.
+    #[arg(long)]
+    raw: bool,
+}
+
 struct App {
     processes: Result<StatefulList<SocketInfo>, NetstatError>,
     system: System,
+    dns_table: dns::IpTable,
+    dns_tx: mpsc::UnboundedSender<IpAddr>,
+    sniffer: Option<Sniffer>,
+    rates: HashMap<LocalSocket, (f64, f64)>,
+    filter: Option<String>,
+    filter_editing: bool,
+    pending_kill: Option<KillPrompt>,
+    keymap: config::Keymap,
+    sorting: config::Sorting,
+}
+
+/// What the confirmation overlay is currently showing.
+enum KillPrompt {
+    Confirm {
+        pid: u32,
+        name: String,
+        signal: sysinfo::Signal,
+    },
+    NoProcess,
 }
 
 impl App {
-    fn new() -> App {
+    fn new(cli: &Cli, config: config::Config) -> App {
         let af_flags = AddressFamilyFlags::all();
         let proto_flags = ProtocolFlags::all();
         let sockets_info = get_sockets_info(af_flags, proto_flags);
 
-        App {
-            processes: sockets_info.map(|s| StatefulList::with_items(s)),
+        let dns_table = dns::IpTable::default();
+        let dns_tx = dns::spawn_resolver(dns_table.clone());
+        if let Ok(sockets) = &sockets_info {
+            dns::queue_unresolved(&dns_table, &dns_tx, remote_ips(sockets));
+        }
+
+        let interface = match &cli.interface {
+            Some(name) => network::find_interface(name),
+            None => network::default_interface(),
+        };
+        let sniffer = match interface {
+            Some(iface) => match Sniffer::spawn(&iface) {
+                Ok(sniffer) => Some(sniffer),
+                Err(e) => {
+                    crate::log!(error, "network: failed to start capture on {}: {e}", iface.name);
+                    None
+                }
+            },
+            None => {
+                crate::log!(error, "network: no usable interface found, bandwidth columns will show \"-\"");
+                None
+            }
+        };
+
+        let mut app = App {
+            processes: sockets_info.map(StatefulList::with_items),
             system: System::new_all(),
+            dns_table,
+            dns_tx,
+            sniffer,
+            rates: HashMap::new(),
+            filter: None,
+            filter_editing: false,
+            pending_kill: None,
+            keymap: config.keymap,
+            sorting: config.sorting,
+        };
+        app.apply_sort();
+        app
+    }
+
+    /// Re-sorts `processes.items` by the active [`config::Sorting`]. Call
+    /// after anything that changes item order or the sort itself.
+    fn apply_sort(&mut self) {
+        if let Ok(processes) = &mut self.processes {
+            sort_sockets(&mut processes.items, self.sorting, &self.rates, &self.system);
         }
     }
 
@@ -41,6 +131,10 @@ impl App {
 
         match new_sockets_info_result {
             Ok(new_sockets_info) => {
+                dns::queue_unresolved(&self.dns_table, &self.dns_tx, remote_ips(&new_sockets_info));
+
+                crate::log!(info, "netstat: refreshed {} sockets", new_sockets_info.len());
+
                 if let Ok(processes) = &mut self.processes {
                     let previously_selected = processes.state.selected();
                     processes.items = new_sockets_info;
@@ -58,15 +152,269 @@ impl App {
                 }
             }
             Err(e) => {
+                crate::log!(error, "netstat: failed to list sockets: {e}");
                 self.processes = Err(e);
             }
         }
         self.system.refresh_all();
+
+        if let Some(sniffer) = &self.sniffer {
+            let utilization = sniffer.snapshot_and_reset();
+            let seconds = TICK_INTERVAL.as_secs_f64();
+            self.rates = utilization
+                .sockets
+                .into_iter()
+                .map(|(socket, (up, down))| (socket, (up as f64 / seconds, down as f64 / seconds)))
+                .collect();
+        }
+
+        self.apply_sort();
+        self.clamp_selection();
+    }
+
+    /// Indices into `processes.items` that match the active filter, in
+    /// their original order. With no filter (or an empty query) every
+    /// index is visible.
+    fn visible_indices(&self) -> Vec<usize> {
+        let Ok(processes) = &self.processes else {
+            return Vec::new();
+        };
+        match self.filter.as_deref() {
+            Some(query) if !query.is_empty() => {
+                let needle = query.to_lowercase();
+                processes
+                    .items
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, socket)| {
+                        format_socket_row(&self.dns_table, &self.rates, socket)
+                            .to_lowercase()
+                            .contains(&needle)
+                    })
+                    .map(|(i, _)| i)
+                    .collect()
+            }
+            _ => (0..processes.items.len()).collect(),
+        }
+    }
+
+    /// Moves the selection by `delta` positions within the filtered view,
+    /// wrapping at either end.
+    fn move_selection(&mut self, delta: isize) {
+        let visible = self.visible_indices();
+        let Ok(processes) = &mut self.processes else {
+            return;
+        };
+        if visible.is_empty() {
+            processes.state.select(None);
+            return;
+        }
+        let len = visible.len() as isize;
+        let current = processes.state.selected().unwrap_or(0) as isize;
+        let next = ((current + delta) % len + len) % len;
+        processes.state.select(Some(next as usize));
+    }
+
+    /// Keeps the selection (an index into the filtered view) in bounds
+    /// after the filter or the underlying socket list changes.
+    fn clamp_selection(&mut self) {
+        let visible_len = self.visible_indices().len();
+        if let Ok(processes) = &mut self.processes {
+            match processes.state.selected() {
+                _ if visible_len == 0 => processes.state.select(None),
+                Some(i) if i >= visible_len => processes.state.select(Some(visible_len - 1)),
+                None => processes.state.select(Some(0)),
+                _ => {}
+            }
+        }
+    }
+
+    /// The socket currently highlighted in the filtered view, if any.
+    fn selected_socket(&self) -> Option<&SocketInfo> {
+        let processes = self.processes.as_ref().ok()?;
+        let visible = self.visible_indices();
+        let selected = processes.state.selected()?;
+        let index = *visible.get(selected)?;
+        processes.items.get(index)
+    }
+
+    /// Opens the confirmation overlay for `signal` against the PID owning
+    /// the selected socket. Shared sockets act on the first associated PID;
+    /// sockets with none surface a dedicated message instead of a no-op.
+    fn begin_kill(&mut self, signal: sysinfo::Signal) {
+        let Some(socket) = self.selected_socket() else {
+            return;
+        };
+        self.pending_kill = Some(match socket.associated_pids.first() {
+            Some(&pid) => {
+                let name = self
+                    .system
+                    .process(sysinfo::Pid::from_u32(pid))
+                    .map(|p| p.name().to_string())
+                    .unwrap_or_else(|| format!("pid {pid}"));
+                KillPrompt::Confirm { pid, name, signal }
+            }
+            None => KillPrompt::NoProcess,
+        });
+    }
+
+    /// Sends the pending signal and immediately refreshes so the killed
+    /// socket disappears without waiting for the next tick.
+    fn confirm_kill(&mut self) {
+        if let Some(KillPrompt::Confirm { pid, name, signal }) = self.pending_kill.take() {
+            match self.system.process(sysinfo::Pid::from_u32(pid)) {
+                Some(process) => match process.kill_with(signal) {
+                    Some(true) => crate::log!(info, "process: sent {signal:?} to {name} (pid {pid})"),
+                    Some(false) => {
+                        crate::log!(error, "process: {signal:?} to {name} (pid {pid}) was not delivered")
+                    }
+                    None => crate::log!(error, "process: {signal:?} is not supported on this platform"),
+                },
+                None => crate::log!(error, "process: pid {pid} no longer exists"),
+            }
+            self.update();
+        }
+    }
+}
+
+/// Formats one socket row the same way for rendering and for filter
+/// matching, so `/` search matches exactly what's on screen.
+fn format_socket_row(dns_table: &dns::IpTable, rates: &HashMap<LocalSocket, (f64, f64)>, socket: &SocketInfo) -> String {
+    match &socket.protocol_socket_info {
+        ProtocolSocketInfo::Tcp(tcp) => {
+            let remote_host = dns_table
+                .hostname_for(&tcp.remote_addr)
+                .unwrap_or_else(|| tcp.remote_addr.to_string());
+            let rates = rate_columns(rate_for(rates, tcp.local_port, Protocol::Tcp));
+            format!(
+                "TCP {}:{} -> {}:{} {:?} - {} {}",
+                tcp.local_addr,
+                tcp.local_port,
+                remote_host,
+                tcp.remote_port,
+                socket.associated_pids,
+                tcp.state,
+                rates
+            )
+        }
+        ProtocolSocketInfo::Udp(udp) => {
+            let rates = rate_columns(rate_for(rates, udp.local_port, Protocol::Udp));
+            format!(
+                "UDP {}:{} -> *:* {:?} {}",
+                udp.local_addr, udp.local_port, socket.associated_pids, rates
+            )
+        }
+    }
+}
+
+/// Sorts sockets in place by the configured column and direction.
+fn sort_sockets(items: &mut [SocketInfo], sorting: config::Sorting, rates: &HashMap<LocalSocket, (f64, f64)>, system: &System) {
+    items.sort_by(|a, b| {
+        let ordering = match sorting.key {
+            config::SortKey::Port => local_port(a).cmp(&local_port(b)),
+            config::SortKey::Pid => pid_of(a).cmp(&pid_of(b)),
+            config::SortKey::ProcessName => process_name(a, system).cmp(&process_name(b, system)),
+            config::SortKey::State => state_of(a).cmp(&state_of(b)),
+            config::SortKey::Rate => total_rate(a, rates)
+                .partial_cmp(&total_rate(b, rates))
+                .unwrap_or(std::cmp::Ordering::Equal),
+        };
+        match sorting.direction {
+            config::SortDirection::Ascending => ordering,
+            config::SortDirection::Descending => ordering.reverse(),
+        }
+    });
+}
+
+fn local_port(socket: &SocketInfo) -> u16 {
+    match &socket.protocol_socket_info {
+        ProtocolSocketInfo::Tcp(tcp) => tcp.local_port,
+        ProtocolSocketInfo::Udp(udp) => udp.local_port,
+    }
+}
+
+fn pid_of(socket: &SocketInfo) -> u32 {
+    socket.associated_pids.first().copied().unwrap_or(u32::MAX)
+}
+
+fn process_name(socket: &SocketInfo, system: &System) -> String {
+    socket
+        .associated_pids
+        .first()
+        .and_then(|&pid| system.process(sysinfo::Pid::from_u32(pid)))
+        .map(|p| p.name().to_string())
+        .unwrap_or_default()
+}
+
+fn state_of(socket: &SocketInfo) -> String {
+    match &socket.protocol_socket_info {
+        ProtocolSocketInfo::Tcp(tcp) => format!("{:?}", tcp.state),
+        ProtocolSocketInfo::Udp(_) => String::new(),
+    }
+}
+
+fn total_rate(socket: &SocketInfo, rates: &HashMap<LocalSocket, (f64, f64)>) -> f64 {
+    let (protocol, port) = match &socket.protocol_socket_info {
+        ProtocolSocketInfo::Tcp(tcp) => (Protocol::Tcp, tcp.local_port),
+        ProtocolSocketInfo::Udp(udp) => (Protocol::Udp, udp.local_port),
+    };
+    rate_for(rates, port, protocol).map(|(up, down)| up + down).unwrap_or(0.0)
+}
+
+/// Up/down bytes-per-second rate for a socket, if the bandwidth subsystem
+/// is active and has seen traffic for it. Takes the rate map directly
+/// (rather than `&App`) so it can be called while another field of `App`
+/// is already borrowed, e.g. from inside the `ui` rendering match.
+fn rate_for(rates: &HashMap<LocalSocket, (f64, f64)>, local_port: u16, protocol: Protocol) -> Option<(f64, f64)> {
+    rates.get(&LocalSocket { local_port, protocol }).copied()
+}
+
+/// Distinct remote addresses worth reverse-resolving, i.e. every TCP peer.
+fn remote_ips(sockets: &[SocketInfo]) -> impl Iterator<Item = &IpAddr> {
+    let mut seen = std::collections::HashSet::new();
+    sockets
+        .iter()
+        .filter_map(|s| match &s.protocol_socket_info {
+            ProtocolSocketInfo::Tcp(tcp) => Some(&tcp.remote_addr),
+            ProtocolSocketInfo::Udp(_) => None,
+        })
+        .filter(move |ip| seen.insert(**ip))
+}
+
+/// Renders the up/down columns for a row, falling back to "-" when the
+/// bandwidth subsystem has no capture permissions or no traffic yet.
+fn rate_columns(rate: Option<(f64, f64)>) -> String {
+    match rate {
+        Some((up, down)) => format!("↑{} ↓{}", format_rate(up), format_rate(down)),
+        None => "↑- ↓-".to_string(),
+    }
+}
+
+/// Renders a bytes/sec count as a short human-readable string, e.g. "4.2 MB/s".
+fn format_rate(bytes_per_sec: f64) -> String {
+    const UNITS: [&str; 5] = ["B/s", "KB/s", "MB/s", "GB/s", "TB/s"];
+    let mut value = bytes_per_sec;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
     }
+    format!("{:.1} {}", value, UNITS[unit])
 }
 
 #[tokio::main]
 async fn main() -> io::Result<()> {
+    let cli = Cli::parse();
+    if let Some(path) = &cli.log_to {
+        if let Err(e) = logging::init_file_logger(path) {
+            eprintln!("failed to open log file {}: {e}", path.display());
+        }
+    }
+
+    if cli.raw {
+        return run_raw(cli).await;
+    }
+
     // setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -75,7 +423,7 @@ async fn main() -> io::Result<()> {
     let mut terminal = Terminal::new(backend)?;
 
     // create app and run it
-    let app = App::new();
+    let app = App::new(&cli, config::load());
     let res = run_app(&mut terminal, app).await;
 
     // restore terminal
@@ -94,8 +442,78 @@ async fn main() -> io::Result<()> {
     Ok(())
 }
 
+/// Headless loop for `--raw`: no alternate screen, no input handling, just
+/// a TSV snapshot on stdout every tick. Reuses `App` wholesale so the
+/// socket/DNS/bandwidth enrichment is identical to the TUI.
+async fn run_raw(cli: Cli) -> io::Result<()> {
+    let mut app = App::new(&cli, config::load());
+    let mut ticker = interval(TICK_INTERVAL);
+    loop {
+        ticker.tick().await;
+        app.update();
+        print_raw_snapshot(&app);
+    }
+}
+
+fn print_raw_snapshot(app: &App) {
+    use std::io::Write;
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+
+    let Ok(processes) = &app.processes else {
+        let _ = writeln!(out, "error\tfailed to fetch socket information");
+        let _ = out.flush();
+        return;
+    };
+
+    for socket in &processes.items {
+        let (protocol, local, remote, state, local_port, transport) = match &socket.protocol_socket_info {
+            ProtocolSocketInfo::Tcp(tcp) => {
+                let remote_host = app
+                    .dns_table
+                    .hostname_for(&tcp.remote_addr)
+                    .unwrap_or_else(|| tcp.remote_addr.to_string());
+                (
+                    "TCP",
+                    format!("{}:{}", tcp.local_addr, tcp.local_port),
+                    format!("{}:{}", remote_host, tcp.remote_port),
+                    format!("{:?}", tcp.state),
+                    tcp.local_port,
+                    Protocol::Tcp,
+                )
+            }
+            ProtocolSocketInfo::Udp(udp) => (
+                "UDP",
+                format!("{}:{}", udp.local_addr, udp.local_port),
+                "*:*".to_string(),
+                "-".to_string(),
+                udp.local_port,
+                Protocol::Udp,
+            ),
+        };
+
+        let pid = socket.associated_pids.first().copied();
+        let process_name = pid
+            .and_then(|p| app.system.process(sysinfo::Pid::from_u32(p)))
+            .map(|p| p.name().to_string())
+            .unwrap_or_else(|| "-".to_string());
+        let (up, down) = match rate_for(&app.rates, local_port, transport) {
+            Some((up, down)) => (format_rate(up), format_rate(down)),
+            None => ("-".to_string(), "-".to_string()),
+        };
+
+        let _ = writeln!(
+            out,
+            "{protocol}\t{local}\t{remote}\t{state}\t{}\t{process_name}\t{up}\t{down}",
+            pid.map(|p| p.to_string()).unwrap_or_else(|| "-".to_string())
+        );
+    }
+    let _ = out.flush();
+}
+
 async fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> io::Result<()> {
-    let mut ticker = interval(Duration::from_secs(2));
+    let mut ticker = interval(TICK_INTERVAL);
 
     loop {
         terminal.draw(|f| ui(f, &mut app))?;
@@ -114,19 +532,54 @@ async fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> io::Re
             },
             res = event => {
                 if let Ok(Ok(Some(Event::Key(key)))) = res {
-                     match key.code {
-                        KeyCode::Char('q') => return Ok(()),
-                        KeyCode::Down => {
-                            if let Ok(processes) = &mut app.processes {
-                                processes.next();
+                    if app.pending_kill.is_some() {
+                        match key.code {
+                            KeyCode::Char('y') => app.confirm_kill(),
+                            _ => app.pending_kill = None,
+                        }
+                    } else if app.filter_editing {
+                        match key.code {
+                            KeyCode::Esc => {
+                                app.filter = None;
+                                app.filter_editing = false;
+                                app.clamp_selection();
+                            }
+                            KeyCode::Enter => {
+                                if app.filter.as_deref() == Some("") {
+                                    app.filter = None;
+                                }
+                                app.filter_editing = false;
+                            }
+                            KeyCode::Backspace => {
+                                if let Some(query) = &mut app.filter {
+                                    query.pop();
+                                }
+                                app.clamp_selection();
                             }
+                            KeyCode::Char(c) => {
+                                if let Some(query) = &mut app.filter {
+                                    query.push(c);
+                                }
+                                app.clamp_selection();
+                            }
+                            _ => {}
                         }
-                        KeyCode::Up => {
-                            if let Ok(processes) = &mut app.processes {
-                                processes.previous();
+                    } else if let Some(action) = app.keymap.resolve(key.code, key.modifiers) {
+                        match action {
+                            Action::Quit => return Ok(()),
+                            Action::Filter => {
+                                app.filter = Some(String::new());
+                                app.filter_editing = true;
+                            }
+                            Action::Down => app.move_selection(1),
+                            Action::Up => app.move_selection(-1),
+                            Action::KillTerm => app.begin_kill(sysinfo::Signal::Term),
+                            Action::KillForce => app.begin_kill(sysinfo::Signal::Kill),
+                            Action::CycleSort => {
+                                app.sorting.cycle();
+                                app.apply_sort();
                             }
                         }
-                        _ => {}
                     }
                 }
             }
@@ -151,57 +604,57 @@ fn ui(frame: &mut Frame, app: &mut App) {
         ])
         .split(chunks[0]);
 
+    let show_filter_bar = app.filter_editing || app.filter.is_some();
+    let list_area = if show_filter_bar {
+        let filter_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(top_chunks[0]);
+
+        let query = app.filter.as_deref().unwrap_or("");
+        let filter_paragraph = Paragraph::new(query).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(if app.filter_editing { "Filter (Enter to apply, Esc to cancel)" } else { "Filter" }),
+        );
+        frame.render_widget(filter_paragraph, filter_chunks[0]);
+        filter_chunks[1]
+    } else {
+        top_chunks[0]
+    };
+
+    let visible = app.visible_indices();
+
     match &mut app.processes {
         Ok(processes) => {
-            let processes_list_items: Vec<ListItem> = processes
-                .items
+            let processes_list_items: Vec<ListItem> = visible
                 .iter()
-                .map(|c| {
-                    let s = match &c.protocol_socket_info {
-                        ProtocolSocketInfo::Tcp(tcp) => {
-                            format!(
-                                "TCP {}:{} -> {}:{} {:?} - {}",
-                                tcp.local_addr,
-                                tcp.local_port,
-                                tcp.remote_addr,
-                                tcp.remote_port,
-                                c.associated_pids,
-                                tcp.state
-                            )
-                        }
-                        ProtocolSocketInfo::Udp(udp) => {
-                            format!(
-                                "UDP {}:{} -> *:* {:?}",
-                                udp.local_addr, udp.local_port, c.associated_pids
-                            )
-                        }
-                    };
-                    ListItem::new(s)
-                })
+                .map(|&i| ListItem::new(format_socket_row(&app.dns_table, &app.rates, &processes.items[i])))
                 .collect();
 
+            let title = format!("Processes (sort: {:?} {:?})", app.sorting.key, app.sorting.direction);
             let processes_list = List::new(processes_list_items)
-                .block(Block::default().borders(Borders::ALL).title("Processes"))
+                .block(Block::default().borders(Borders::ALL).title(title))
                 .highlight_style(
                     Style::default()
                         .bg(Color::Blue)
                         .add_modifier(Modifier::BOLD),
                 )
                 .highlight_symbol(">> ");
-            frame.render_stateful_widget(processes_list, top_chunks[0], &mut processes.state);
+            frame.render_stateful_widget(processes_list, list_area, &mut processes.state);
         }
         Err(e) => {
             let error_message = format!("Error fetching socket information: {}", e);
             let block = Block::default().title("Error").borders(Borders::ALL);
             let paragraph = Paragraph::new(error_message).block(block);
-            frame.render_widget(paragraph, top_chunks[0]);
+            frame.render_widget(paragraph, list_area);
         }
     }
 
     let details_block = Block::default().borders(Borders::ALL).title("Details");
     let details_text = if let Ok(processes) = &app.processes {
-        if let Some(selected) = processes.state.selected() {
-            let socket_info = &processes.items[selected];
+        if let Some(selected) = processes.state.selected().and_then(|i| visible.get(i)) {
+            let socket_info = &processes.items[*selected];
             let pids = &socket_info.associated_pids;
             if let Some(pid) = pids.first() {
                 if let Some(process) = app.system.process(sysinfo::Pid::from_u32(*pid)) {
@@ -226,8 +679,56 @@ fn ui(frame: &mut Frame, app: &mut App) {
     };
     let details_paragraph = Paragraph::new(details_text).block(details_block);
     frame.render_widget(details_paragraph, top_chunks[1]);
-    frame.render_widget(
-        Block::new().borders(Borders::ALL).title("Logs"),
-        chunks[1],
-    );
+    let logs_block = Block::default().borders(Borders::ALL).title("Logs");
+    let visible_rows = chunks[1].height.saturating_sub(2) as usize;
+    let entries = logging::recent(visible_rows.max(1));
+    let log_lines: Vec<Line> = entries
+        .iter()
+        .map(|entry| {
+            let style = match entry.level {
+                logging::Level::Error => Style::default().fg(Color::Red),
+                logging::Level::Info => Style::default().add_modifier(Modifier::DIM),
+            };
+            Line::styled(entry.line.clone(), style)
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(log_lines).block(logs_block), chunks[1]);
+
+    if let Some(prompt) = &app.pending_kill {
+        let area = centered_rect(50, 20, frame.size());
+        let text = match prompt {
+            KillPrompt::Confirm { pid, name, signal } => {
+                let verb = match signal {
+                    sysinfo::Signal::Kill => "Kill",
+                    _ => "Terminate",
+                };
+                format!("{verb} {name} (pid {pid})?\n\ny/n")
+            }
+            KillPrompt::NoProcess => "No process associated with this socket.".to_string(),
+        };
+        let popup = Paragraph::new(text).block(Block::default().borders(Borders::ALL).title("Confirm"));
+        frame.render_widget(Clear, area);
+        frame.render_widget(popup, area);
+    }
+}
+
+/// A rectangle of `percent_x` by `percent_y` centered within `r`.
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(vertical[1])[1]
 }
@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+use std::io;
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+
+use pnet::datalink::{self, Channel, NetworkInterface};
+use pnet::packet::ethernet::{EtherTypes, EthernetPacket};
+use pnet::packet::ip::IpNextHeaderProtocols;
+use pnet::packet::ipv4::Ipv4Packet;
+use pnet::packet::ipv6::Ipv6Packet;
+use pnet::packet::tcp::TcpPacket;
+use pnet::packet::udp::UdpPacket;
+use pnet::packet::Packet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Protocol {
+    Tcp,
+    Udp,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LocalSocket {
+    pub local_port: u16,
+    pub protocol: Protocol,
+}
+
+enum Direction {
+    Upload,
+    Download,
+}
+
+/// Bytes seen per local socket since the last [`Sniffer::snapshot_and_reset`],
+/// as `(bytes_up, bytes_down)`.
+#[derive(Default)]
+pub struct Utilization {
+    pub sockets: HashMap<LocalSocket, (u64, u64)>,
+}
+
+impl Utilization {
+    fn record(&mut self, socket: LocalSocket, direction: Direction, bytes: u64) {
+        let entry = self.sockets.entry(socket).or_insert((0, 0));
+        match direction {
+            Direction::Upload => entry.0 += bytes,
+            Direction::Download => entry.1 += bytes,
+        }
+    }
+}
+
+/// Picks the first interface that's up and not loopback, mirroring the
+/// default most packet-capture CLIs use when `--interface` is omitted.
+pub fn default_interface() -> Option<NetworkInterface> {
+    datalink::interfaces()
+        .into_iter()
+        .find(|iface| iface.is_up() && !iface.is_loopback())
+}
+
+pub fn find_interface(name: &str) -> Option<NetworkInterface> {
+    datalink::interfaces().into_iter().find(|iface| iface.name == name)
+}
+
+/// Captures raw packets on one interface in a background thread and
+/// attributes their byte counts to the owning local socket.
+pub struct Sniffer {
+    accumulator: Arc<Mutex<Utilization>>,
+}
+
+impl Sniffer {
+    /// Spawns the capture loop. Returns `Err` when the interface can't be
+    /// opened (commonly a permissions problem), so callers can degrade to
+    /// showing "-" instead of failing the whole app.
+    pub fn spawn(interface: &NetworkInterface) -> io::Result<Self> {
+        let channel = datalink::channel(interface, Default::default())
+            .map_err(|e| io::Error::new(io::ErrorKind::PermissionDenied, e))?;
+        let mut rx = match channel {
+            Channel::Ethernet(_, rx) => rx,
+            _ => return Err(io::Error::other("unsupported channel type")),
+        };
+
+        let local_ips: Vec<IpAddr> = interface.ips.iter().map(|ip| ip.ip()).collect();
+        let accumulator = Arc::new(Mutex::new(Utilization::default()));
+        let accumulator_writer = accumulator.clone();
+
+        std::thread::spawn(move || loop {
+            match rx.next() {
+                Ok(packet) => {
+                    if let Some(ethernet) = EthernetPacket::new(packet) {
+                        record_packet(&ethernet, &local_ips, &accumulator_writer);
+                    }
+                }
+                Err(e) => {
+                    crate::log!(error, "network: capture loop stopped: {e}");
+                    break;
+                }
+            }
+        });
+
+        Ok(Sniffer { accumulator })
+    }
+
+    /// Returns the bytes accumulated since the last call and resets the
+    /// counters, so the caller can divide by the elapsed interval to get a
+    /// bytes/sec rate.
+    pub fn snapshot_and_reset(&self) -> Utilization {
+        let mut guard = self.accumulator.lock().unwrap();
+        std::mem::take(&mut *guard)
+    }
+}
+
+fn record_packet(ethernet: &EthernetPacket, local_ips: &[IpAddr], accumulator: &Arc<Mutex<Utilization>>) {
+    match ethernet.get_ethertype() {
+        EtherTypes::Ipv4 => {
+            if let Some(ipv4) = Ipv4Packet::new(ethernet.payload()) {
+                let src = IpAddr::V4(ipv4.get_source());
+                let dst = IpAddr::V4(ipv4.get_destination());
+                record_transport(ipv4.get_next_level_protocol(), ipv4.payload(), src, dst, local_ips, accumulator);
+            }
+        }
+        EtherTypes::Ipv6 => {
+            if let Some(ipv6) = Ipv6Packet::new(ethernet.payload()) {
+                let src = IpAddr::V6(ipv6.get_source());
+                let dst = IpAddr::V6(ipv6.get_destination());
+                record_transport(ipv6.get_next_header(), ipv6.payload(), src, dst, local_ips, accumulator);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn record_transport(
+    next_proto: pnet::packet::ip::IpNextHeaderProtocol,
+    payload: &[u8],
+    src: IpAddr,
+    dst: IpAddr,
+    local_ips: &[IpAddr],
+    accumulator: &Arc<Mutex<Utilization>>,
+) {
+    let (protocol, src_port, dst_port, len) = match next_proto {
+        IpNextHeaderProtocols::Tcp => match TcpPacket::new(payload) {
+            Some(tcp) => (Protocol::Tcp, tcp.get_source(), tcp.get_destination(), payload.len() as u64),
+            None => return,
+        },
+        IpNextHeaderProtocols::Udp => match UdpPacket::new(payload) {
+            Some(udp) => (Protocol::Udp, udp.get_source(), udp.get_destination(), payload.len() as u64),
+            None => return,
+        },
+        _ => return,
+    };
+
+    let (direction, local_port) = if local_ips.contains(&src) {
+        (Direction::Upload, src_port)
+    } else if local_ips.contains(&dst) {
+        (Direction::Download, dst_port)
+    } else {
+        return;
+    };
+
+    accumulator
+        .lock()
+        .unwrap()
+        .record(LocalSocket { local_port, protocol }, direction, len);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pnet::packet::tcp::MutableTcpPacket;
+    use pnet::packet::udp::MutableUdpPacket;
+    use std::net::Ipv4Addr;
+
+    fn tcp_packet(src_port: u16, dst_port: u16) -> Vec<u8> {
+        let mut buf = vec![0u8; TcpPacket::minimum_packet_size()];
+        let mut tcp = MutableTcpPacket::new(&mut buf).unwrap();
+        tcp.set_source(src_port);
+        tcp.set_destination(dst_port);
+        buf
+    }
+
+    fn udp_packet(src_port: u16, dst_port: u16) -> Vec<u8> {
+        let mut buf = vec![0u8; UdpPacket::minimum_packet_size()];
+        let mut udp = MutableUdpPacket::new(&mut buf).unwrap();
+        udp.set_source(src_port);
+        udp.set_destination(dst_port);
+        buf
+    }
+
+    #[test]
+    fn outbound_tcp_is_recorded_as_upload_under_the_local_port() {
+        let local_ip = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 10));
+        let remote_ip = IpAddr::V4(Ipv4Addr::new(93, 184, 216, 34));
+        let packet = tcp_packet(54321, 443);
+        let accumulator = Arc::new(Mutex::new(Utilization::default()));
+
+        record_transport(
+            IpNextHeaderProtocols::Tcp,
+            &packet,
+            local_ip,
+            remote_ip,
+            &[local_ip],
+            &accumulator,
+        );
+
+        let socket = LocalSocket { local_port: 54321, protocol: Protocol::Tcp };
+        let (up, down) = accumulator.lock().unwrap().sockets[&socket];
+        assert_eq!((up, down), (packet.len() as u64, 0));
+    }
+
+    #[test]
+    fn inbound_udp_is_recorded_as_download_under_the_local_port() {
+        let local_ip = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 10));
+        let remote_ip = IpAddr::V4(Ipv4Addr::new(8, 8, 8, 8));
+        let packet = udp_packet(53, 60000);
+        let accumulator = Arc::new(Mutex::new(Utilization::default()));
+
+        record_transport(
+            IpNextHeaderProtocols::Udp,
+            &packet,
+            remote_ip,
+            local_ip,
+            &[local_ip],
+            &accumulator,
+        );
+
+        let socket = LocalSocket { local_port: 60000, protocol: Protocol::Udp };
+        let (up, down) = accumulator.lock().unwrap().sockets[&socket];
+        assert_eq!((up, down), (0, packet.len() as u64));
+    }
+
+    #[test]
+    fn traffic_between_two_remote_hosts_is_ignored() {
+        let local_ip = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 10));
+        let remote_a = IpAddr::V4(Ipv4Addr::new(1, 1, 1, 1));
+        let remote_b = IpAddr::V4(Ipv4Addr::new(8, 8, 8, 8));
+        let packet = tcp_packet(1234, 443);
+        let accumulator = Arc::new(Mutex::new(Utilization::default()));
+
+        record_transport(
+            IpNextHeaderProtocols::Tcp,
+            &packet,
+            remote_a,
+            remote_b,
+            &[local_ip],
+            &accumulator,
+        );
+
+        assert!(accumulator.lock().unwrap().sockets.is_empty());
+    }
+}
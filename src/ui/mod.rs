@@ -0,0 +1 @@
+pub mod stateful_list;